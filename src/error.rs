@@ -0,0 +1,49 @@
+//! Error type returned by the fallible `Config::try_*` APIs.
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// Describes why a fallible config operation failed.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// No file with a supported extension was found for the config name.
+    FileNotFound(PathBuf),
+    /// The file was found but its contents couldn't be parsed.
+    Parse {
+        path: PathBuf,
+        /// Human-readable format label, e.g. `"JSON"`, `"TOML"` or `"YAML"`.
+        format: &'static str,
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    /// The value at a path didn't match the type requested by the caller.
+    TypeMismatch { path: String, message: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::FileNotFound(path) => {
+                write!(f, "Failed to read config file: {}", path.display())
+            }
+            ConfigError::Parse { path, format, message, line, column } => match (line, column) {
+                (Some(line), Some(column)) => write!(
+                    f,
+                    "Invalid {} format in {} at line {}, column {}: {}",
+                    format,
+                    path.display(),
+                    line,
+                    column,
+                    message
+                ),
+                _ => write!(f, "Invalid {} format in {}: {}", format, path.display(), message),
+            },
+            ConfigError::TypeMismatch { path, message } => {
+                write!(f, "Type mismatch at \"{}\": {}", path, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
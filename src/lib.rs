@@ -1,6 +1,6 @@
-//! A thread-safe configuration management library with JSON file support.
+//! A thread-safe configuration management library with JSON, TOML and YAML file support.
 //!
-//! This module provides a simple way to load and access configuration values from JSON files
+//! This module provides a simple way to load and access configuration values from files
 //! stored in a `configs/` directory. Configurations are cached globally for efficient access.
 //!
 //! # Examples
@@ -17,10 +17,18 @@
 //! ```
 use std::fs;
 
+mod error;
+pub use error::ConfigError;
+
 use lazy_static::lazy_static;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 use std::sync::RwLock;
+use std::thread;
+use std::time::Duration;
 use serde::de::DeserializeOwned;
 
 pub trait ConfigModule {
@@ -32,6 +40,34 @@ lazy_static! {
     static ref CONFIGS: RwLock<HashMap<String, Value>> = RwLock::new(HashMap::new());
 }
 
+// Global runtime overrides, keyed by config name, consulted before the file-backed cache.
+lazy_static! {
+    static ref OVERRIDES: RwLock<HashMap<String, Value>> = RwLock::new(HashMap::new());
+}
+
+// Per-name reload counters, bumped on every successful reload and read by `Config::generation`.
+lazy_static! {
+    static ref GENERATIONS: RwLock<HashMap<String, u64>> = RwLock::new(HashMap::new());
+}
+
+// Per-name provenance, mapping each dotted key path (including "" for the
+// config root) to the file that last defined it.
+lazy_static! {
+    static ref ORIGINS: RwLock<HashMap<String, HashMap<String, PathBuf>>> = RwLock::new(HashMap::new());
+}
+
+/// Where a resolved configuration value came from, as returned by
+/// [`Config::get_with_origin`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Origin {
+    /// Set via the environment-variable override layer.
+    EnvOverride,
+    /// Set via [`Config::set_override`] or [`Config::with_overrides`].
+    RuntimeOverride,
+    /// Loaded from the given file on disk.
+    File(PathBuf),
+}
+
 /// Configuration instance that provides access to cached configuration values
 ///
 /// Each `Config` instance is associated with a specific configuration file
@@ -43,15 +79,16 @@ pub struct Config {
 impl Config {
     /// Creates or retrieves a cached configuration instance
     ///
-    /// The configuration is loaded from `configs/{name}.json`. The file is parsed
-    /// only once and then cached for subsequent accesses.
+    /// The configuration is loaded from `configs/{name}.json`, `configs/{name}.toml`,
+    /// `configs/{name}.yaml` or `configs/{name}.yml`, whichever exists first in that
+    /// order. The file is parsed only once and then cached for subsequent accesses.
     ///
     /// # Arguments
     /// * `name` - Name of the configuration file (without extension)
     ///
     /// # Panics
-    /// - If the configuration file doesn't exist in `configs/` directory
-    /// - If the file contains invalid JSON
+    /// - If no configuration file with a supported extension exists in `configs/`
+    /// - If the file contents don't match its extension's format
     ///
     /// # Examples
     /// ```
@@ -59,17 +96,72 @@ impl Config {
     /// let config = Config::new("app_settings");
     /// ```
     pub fn new(name: &str) -> Self {
+        Self::try_new(name).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fallible counterpart of [`Config::new`].
+    ///
+    /// Returns a [`ConfigError`] instead of panicking when the config file is
+    /// missing or fails to parse, so library code can surface the failure to
+    /// its caller instead of aborting the process.
+    ///
+    /// # Examples
+    /// ```
+    /// use config_ro::Config;
+    /// match Config::try_new("app_settings") {
+    ///     Ok(config) => println!("loaded config"),
+    ///     Err(err) => eprintln!("could not load config: {}", err),
+    /// }
+    /// ```
+    pub fn try_new(name: &str) -> Result<Self, ConfigError> {
         let has = {
             let configs = CONFIGS.read().unwrap();
             configs.get(name).is_some()
         };
         if !has {
-            let mut configs = CONFIGS.write().unwrap();
-            configs.insert(name.to_string(), from_name(name));
+            let (value, origin) = try_load_named(name)?;
+            CONFIGS.write().unwrap().insert(name.to_string(), value.clone());
+            record_origins(name, &value, &origin);
         }
-        Config {
+        Ok(Config {
             name: name.to_string(),
+        })
+    }
+
+    /// Loads and deep-merges several config files into a single cached config,
+    /// with later names overriding earlier ones.
+    ///
+    /// Supports the common base-plus-environment pattern, e.g.
+    /// `Config::layered(&["defaults", "production"])`. Merge semantics: two
+    /// objects are merged key-by-key; for scalars, arrays or type mismatches
+    /// the higher-priority (later) value wins outright. The merged result is
+    /// cached under a composite key so `get` works identically to a
+    /// single-file `Config`.
+    ///
+    /// # Panics
+    /// - If any of the named configuration files is missing or invalid
+    ///
+    /// # Examples
+    /// ```
+    /// use config_ro::Config;
+    /// let config = Config::layered(&["defaults", "production"]);
+    /// ```
+    pub fn layered(names: &[&str]) -> Self {
+        let composite_name = names.join("+");
+        let has = {
+            let configs = CONFIGS.read().unwrap();
+            configs.get(&composite_name).is_some()
+        };
+        if !has {
+            let mut merged = Value::Object(serde_json::Map::new());
+            for name in names {
+                let (value, origin) = load_named(name);
+                record_origins(&composite_name, &value, &origin);
+                merge_values(&mut merged, value);
+            }
+            CONFIGS.write().unwrap().insert(composite_name.clone(), merged);
         }
+        Config { name: composite_name }
     }
 
     /// Retrieves a configuration value by its path, supporting nested structures
@@ -98,26 +190,464 @@ impl Config {
     /// let retry_count: Option<u8> = config.get("retries.count");
     /// ```
     pub fn get<T: DeserializeOwned>(&self, path: &str) -> Option<T> {
+        self.try_get(path).ok().flatten()
+    }
+
+    /// Fallible counterpart of [`Config::get`].
+    ///
+    /// Returns `Ok(None)` if `path` doesn't resolve to a value, and
+    /// `Err(ConfigError::TypeMismatch)` if it resolves but doesn't
+    /// deserialize to `T`, instead of silently discarding the distinction
+    /// between the two as `get` does.
+    ///
+    /// # Examples
+    /// ```
+    /// use config_ro::Config;
+    /// let config = Config::new("app");
+    /// let timeout: Option<u32> = config.try_get("timeout").unwrap();
+    /// ```
+    pub fn try_get<T: DeserializeOwned>(&self, path: &str) -> Result<Option<T>, ConfigError> {
+        if let Some(value) = walk_path(OVERRIDES.read().unwrap().get(&self.name), path) {
+            return deserialize_at(value.clone(), path);
+        }
+
+        if let Some(value) = env_override(&self.name, path) {
+            return deserialize_at(value, path);
+        }
+
         let configs = CONFIGS.read().unwrap();
-        let value = configs.get(&self.name)?;
+        let Some(value) = configs.get(&self.name) else {
+            return Ok(None);
+        };
+
+        match walk_path(Some(value), path) {
+            Some(value) => deserialize_at(value.clone(), path),
+            None => Ok(None),
+        }
+    }
+
+    /// Like [`Config::get`], but also reports where the winning value came
+    /// from: an environment-variable override, a runtime override, or a
+    /// specific file on disk.
+    ///
+    /// For layered configs, the reported file is whichever of the merged
+    /// files most recently defined `path` or one of its ancestor objects.
+    ///
+    /// # Examples
+    /// ```
+    /// use config_ro::{Config, Origin};
+    /// let config = Config::new("app_settings");
+    /// if let Some((port, origin)) = config.get_with_origin::<u16>("database.port") {
+    ///     println!("port {} came from {:?}", port, origin);
+    /// }
+    /// ```
+    pub fn get_with_origin<T: DeserializeOwned>(&self, path: &str) -> Option<(T, Origin)> {
+        if let Some(value) = walk_path(OVERRIDES.read().unwrap().get(&self.name), path) {
+            let parsed = serde_json::from_value(value.clone()).ok()?;
+            return Some((parsed, Origin::RuntimeOverride));
+        }
+
+        if let Some(value) = env_override(&self.name, path) {
+            let parsed = serde_json::from_value(value).ok()?;
+            return Some((parsed, Origin::EnvOverride));
+        }
+
+        let parsed = {
+            let configs = CONFIGS.read().unwrap();
+            let value = configs.get(&self.name)?;
+            serde_json::from_value::<T>(walk_path(Some(value), path)?.clone()).ok()?
+        };
+
+        let origins = ORIGINS.read().unwrap();
+        let origin = nearest_origin(origins.get(&self.name)?, path)?.clone();
+        Some((parsed, Origin::File(origin)))
+    }
+
+    /// Sets a single runtime override for this config, taking precedence over
+    /// both the environment-variable layer and the cached file value.
+    ///
+    /// `path` uses the same dot notation as [`Config::get`]. Intermediate
+    /// objects are created as needed.
+    ///
+    /// # Examples
+    /// ```
+    /// use config_ro::Config;
+    /// use serde_json::json;
+    ///
+    /// let config = Config::new("app");
+    /// config.set_override("database.port", json!(5433));
+    /// ```
+    pub fn set_override(&self, path: &str, value: Value) {
+        let mut overrides = OVERRIDES.write().unwrap();
+        let root = overrides.entry(self.name.clone()).or_insert_with(|| Value::Object(serde_json::Map::new()));
+        insert_path(root, path, value);
+    }
+
+    /// Applies a batch of runtime overrides described as a comma-separated
+    /// list of `path=value` pairs, e.g. `"db.host=localhost,db.port=5432"`.
+    ///
+    /// Each value is parsed as JSON when possible, falling back to a raw
+    /// string otherwise (mirroring the environment-variable override layer).
+    ///
+    /// # Examples
+    /// ```
+    /// use config_ro::Config;
+    ///
+    /// let config = Config::new("app");
+    /// config.with_overrides("db.host=localhost,db.port=5432");
+    /// ```
+    pub fn with_overrides(&self, spec: &str) -> &Self {
+        for (path, raw) in parse_override_pairs(spec) {
+            let value = serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()));
+            self.set_override(path, value);
+        }
+        self
+    }
+
+    /// Re-reads the config file from disk and replaces the cached value.
+    ///
+    /// Readers always see either the previous value or the fully-parsed new
+    /// one, since the cache is only ever replaced as a single write under
+    /// [`CONFIGS`]'s lock. Each reload bumps this config's generation
+    /// counter, returned by [`Config::generation`].
+    ///
+    /// # Panics
+    /// - If the configuration file no longer exists or contains invalid data
+    ///
+    /// # Examples
+    /// ```
+    /// use config_ro::Config;
+    /// let config = Config::new("app_settings");
+    /// config.reload();
+    /// ```
+    pub fn reload(&self) {
+        self.try_reload().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fallible counterpart of [`Config::reload`], used by [`Config::watch`]
+    /// so a transient read error (e.g. a file briefly missing mid-rewrite)
+    /// doesn't panic the background watcher thread.
+    fn try_reload(&self) -> Result<(), ConfigError> {
+        let (value, origin) = try_load_named(&self.name)?;
+        CONFIGS.write().unwrap().insert(self.name.clone(), value.clone());
+        record_origins(&self.name, &value, &origin);
+        *GENERATIONS.write().unwrap().entry(self.name.clone()).or_insert(0) += 1;
+        Ok(())
+    }
+
+    /// Returns how many times this config has been reloaded.
+    pub fn generation(&self) -> u64 {
+        GENERATIONS.read().unwrap().get(&self.name).copied().unwrap_or(0)
+    }
+
+    /// Spawns a background thread that watches `configs/{name}.*` for
+    /// modifications and reloads the config, as [`Config::reload`] does,
+    /// whenever the file changes.
+    ///
+    /// A short delay after the first event in a burst lets the rest of the
+    /// burst arrive (editors that save via temp-write + rename emit several
+    /// events per save), then all queued events are drained so the burst
+    /// triggers a single reload instead of one per event. Reload failures
+    /// (e.g. the file being briefly absent mid-rewrite) are logged to
+    /// stderr and skipped rather than panicking the watcher thread.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use config_ro::Config;
+    /// let config = Config::new("app_settings");
+    /// config.watch();
+    /// ```
+    pub fn watch(&self) {
+        let name = self.name.clone();
+        thread::spawn(move || {
+            let (tx, rx) = mpsc::channel();
+            let mut watcher = match notify::recommended_watcher(move |res: notify::Result<Event>| {
+                if let Ok(event) = res {
+                    let _ = tx.send(event);
+                }
+            }) {
+                Ok(watcher) => watcher,
+                Err(_) => return,
+            };
+
+            if watcher.watch(Path::new("configs"), RecursiveMode::NonRecursive).is_err() {
+                return;
+            }
+
+            while let Ok(event) = rx.recv() {
+                if !is_relevant_modify(&event, &name) {
+                    continue;
+                }
+
+                thread::sleep(Duration::from_millis(50));
+                while rx.try_recv().is_ok() {}
+
+                if let Err(err) = (Config { name: name.clone() }).try_reload() {
+                    eprintln!("config_ro: failed to reload \"{}\": {}", name, err);
+                }
+            }
+        });
+    }
+}
+
+/// Returns whether `event` is a modification of `configs/{name}.*`.
+fn is_relevant_modify(event: &Event, name: &str) -> bool {
+    matches!(event.kind, EventKind::Modify(_))
+        && event
+            .paths
+            .iter()
+            .any(|p| p.file_stem().and_then(|s| s.to_str()) == Some(name))
+}
+
+/// Splits a comma-separated `path=value` spec into `(path, raw_value)` pairs.
+fn parse_override_pairs(spec: &str) -> Vec<(&str, &str)> {
+    spec.split(',')
+        .filter(|pair| !pair.is_empty())
+        .filter_map(|pair| pair.split_once('='))
+        .collect()
+}
+
+/// Inserts `value` into `root` at the given dot-separated `path`, creating
+/// intermediate objects as needed.
+fn insert_path(root: &mut Value, path: &str, value: Value) {
+    let keys: Vec<&str> = path.split('.').collect();
+    insert_keys(root, &keys, value);
+}
+
+fn insert_keys(current: &mut Value, keys: &[&str], value: Value) {
+    if !current.is_object() {
+        *current = Value::Object(serde_json::Map::new());
+    }
+    let map = current.as_object_mut().unwrap();
+    if keys.len() == 1 {
+        map.insert(keys[0].to_string(), value);
+    } else {
+        let entry = map
+            .entry(keys[0].to_string())
+            .or_insert_with(|| Value::Object(serde_json::Map::new()));
+        insert_keys(entry, &keys[1..], value);
+    }
+}
+
+/// Records `origin` as the source of every path in `value` (including the
+/// empty path, for the config root), overwriting any earlier entries at the
+/// same paths. Called once per loaded file, so later files in a layered
+/// config naturally win at whatever paths they define.
+fn record_origins(config_name: &str, value: &Value, origin: &Path) {
+    let mut origins = ORIGINS.write().unwrap();
+    let entry = origins.entry(config_name.to_string()).or_insert_with(HashMap::new);
+    insert_origin(entry, value, "", origin);
+}
 
-        let mut current = value;
-        for key in path.split('.') {
-            current = match current.get(key) {
-                Some(v) => v,
-                None => return None,
+fn insert_origin(map: &mut HashMap<String, PathBuf>, value: &Value, prefix: &str, origin: &Path) {
+    map.insert(prefix.to_string(), origin.to_path_buf());
+    if let Value::Object(object) = value {
+        for (key, child) in object {
+            let child_prefix = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
             };
+            insert_origin(map, child, &child_prefix, origin);
         }
+    }
+}
+
+/// Finds the origin recorded for `path`, falling back to the nearest
+/// ancestor path (and finally the config root) if `path` itself has none.
+fn nearest_origin<'a>(map: &'a HashMap<String, PathBuf>, path: &str) -> Option<&'a PathBuf> {
+    let mut segments: Vec<&str> = path.split('.').collect();
+    while !segments.is_empty() {
+        if let Some(origin) = map.get(&segments.join(".")) {
+            return Some(origin);
+        }
+        segments.pop();
+    }
+    map.get("")
+}
+
+/// Deep-merges `overlay` into `base`: objects are merged key-by-key, while
+/// scalars, arrays and type mismatches are replaced wholesale by `overlay`.
+fn merge_values(base: &mut Value, overlay: Value) {
+    match overlay {
+        Value::Object(overlay_map) => {
+            if let Value::Object(base_map) = base {
+                for (key, value) in overlay_map {
+                    match base_map.get_mut(&key) {
+                        Some(existing) => merge_values(existing, value),
+                        None => {
+                            base_map.insert(key, value);
+                        }
+                    }
+                }
+            } else {
+                *base = Value::Object(overlay_map);
+            }
+        }
+        other => *base = other,
+    }
+}
+
+/// Deserializes `value` as `T`, reporting a mismatch as `ConfigError::TypeMismatch`
+/// tagged with the `path` it was looked up at.
+fn deserialize_at<T: DeserializeOwned>(value: Value, path: &str) -> Result<Option<T>, ConfigError> {
+    serde_json::from_value(value).map(Some).map_err(|err| ConfigError::TypeMismatch {
+        path: path.to_string(),
+        message: err.to_string(),
+    })
+}
+
+/// Walks a dot-separated `path` through `root`, returning the value at the
+/// end of the path if every segment resolves, or `None` otherwise.
+fn walk_path<'a>(root: Option<&'a Value>, path: &str) -> Option<&'a Value> {
+    let mut current = root?;
+    for key in path.split('.') {
+        current = current.get(key)?;
+    }
+    Some(current)
+}
+
+/// Looks up the environment-variable override for `path` within config `name`.
+///
+/// The variable name is derived by uppercasing `name`, uppercasing `path` with
+/// `.` replaced by `__`, replacing `-` with `_` throughout, and joining both
+/// halves with `__` (e.g. `get("database.port")` on `Config::new("app")` looks
+/// up `APP__DATABASE__PORT`). The value is parsed as JSON when possible, and
+/// otherwise treated as a raw string.
+fn env_override(name: &str, path: &str) -> Option<Value> {
+    let var_name = env_var_name(name, path);
+    let raw = std::env::var(var_name).ok()?;
+    Some(serde_json::from_str(&raw).unwrap_or_else(|_| Value::String(raw)))
+}
+
+fn env_var_name(name: &str, path: &str) -> String {
+    let normalize = |s: &str| s.to_uppercase().replace('-', "_");
+    format!("{}__{}", normalize(name), normalize(&path.replace('.', "__")))
+}
+
+/// Extensions tried, in order, when locating a config file for a given name.
+const SUPPORTED_EXTENSIONS: [&str; 4] = ["json", "toml", "yaml", "yml"];
 
-        serde_json::from_value(current.clone()).ok()
+/// Resolves, reads and parses `configs/{name}.*`, returning both the parsed
+/// value and the resolved file path so callers can record provenance.
+///
+/// # Panics
+/// - If no supported config file exists for `name`, or it fails to parse
+fn load_named(name: &str) -> (Value, PathBuf) {
+    match try_load_named(name) {
+        Ok(loaded) => loaded,
+        Err(err) => panic!("{}", err),
     }
 }
 
-fn from_name(name: &str) -> Value {
-    let filename = format!("configs/{}.json", name);
+/// Fallible counterpart of [`load_named`], used by [`Config::try_new`].
+fn try_load_named(name: &str) -> Result<(Value, PathBuf), ConfigError> {
+    let (filename, ext) = SUPPORTED_EXTENSIONS
+        .iter()
+        .map(|ext| (format!("configs/{}.{}", name, ext), *ext))
+        .find(|(filename, _)| Path::new(filename).exists())
+        .ok_or_else(|| ConfigError::FileNotFound(PathBuf::from(format!("configs/{}.{}", name, SUPPORTED_EXTENSIONS[0]))))?;
+
     let content = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read config file: {}", filename));
+        .map_err(|_| ConfigError::FileNotFound(PathBuf::from(&filename)))?;
+
+    let value = try_parse_content(&content, ext, &filename)?;
+    Ok((value, PathBuf::from(filename)))
+}
 
-    serde_json::from_str(&content).unwrap_or_else(|_| panic!("Invalid JSON format in {}", filename))
+/// Parses raw config file content according to its extension, returning a
+/// `serde_json::Value` so downstream lookups stay format-agnostic.
+fn try_parse_content(content: &str, ext: &str, filename: &str) -> Result<Value, ConfigError> {
+    let format = ext_label(ext);
+    match ext {
+        "toml" => content
+            .parse::<toml::Value>()
+            .map(toml_to_json)
+            .map_err(|e| parse_error(filename, format, e.to_string(), None, None)),
+        "yaml" | "yml" => serde_yaml::from_str::<serde_yaml::Value>(content)
+            .map_err(|e| parse_error(filename, format, e.to_string(), None, None))
+            .and_then(|value| {
+                yaml_to_json(value)
+                    .ok_or_else(|| parse_error(filename, format, "non-string mapping key".to_string(), None, None))
+            }),
+        _ => serde_json::from_str(content)
+            .map_err(|e| parse_error(filename, format, e.to_string(), Some(e.line()), Some(e.column()))),
+    }
+}
+
+fn ext_label(ext: &str) -> &'static str {
+    match ext {
+        "toml" => "TOML",
+        "yaml" | "yml" => "YAML",
+        _ => "JSON",
+    }
+}
+
+fn parse_error(
+    filename: &str,
+    format: &'static str,
+    message: String,
+    line: Option<usize>,
+    column: Option<usize>,
+) -> ConfigError {
+    ConfigError::Parse {
+        path: PathBuf::from(filename),
+        format,
+        message,
+        line,
+        column,
+    }
+}
+
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::Number(i.into()),
+        toml::Value::Float(f) => serde_json::Number::from_f64(f)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(d) => Value::String(d.to_string()),
+        toml::Value::Array(arr) => Value::Array(arr.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => {
+            Value::Object(table.into_iter().map(|(k, v)| (k, toml_to_json(v))).collect())
+        }
+    }
+}
+
+/// Converts a parsed YAML document into `serde_json::Value`.
+///
+/// Returns `None` if the document contains a non-string mapping key, since
+/// JSON objects only support string keys.
+fn yaml_to_json(value: serde_yaml::Value) -> Option<Value> {
+    Some(match value {
+        serde_yaml::Value::Null => Value::Null,
+        serde_yaml::Value::Bool(b) => Value::Bool(b),
+        serde_yaml::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::Number(i.into())
+            } else if let Some(f) = n.as_f64() {
+                serde_json::Number::from_f64(f).map(Value::Number).unwrap_or(Value::Null)
+            } else {
+                Value::Null
+            }
+        }
+        serde_yaml::Value::String(s) => Value::String(s),
+        serde_yaml::Value::Sequence(seq) => {
+            Value::Array(seq.into_iter().map(yaml_to_json).collect::<Option<Vec<_>>>()?)
+        }
+        serde_yaml::Value::Mapping(map) => {
+            let mut object = serde_json::Map::new();
+            for (k, v) in map {
+                let key = match k {
+                    serde_yaml::Value::String(s) => s,
+                    _ => return None,
+                };
+                object.insert(key, yaml_to_json(v)?);
+            }
+            Value::Object(object)
+        }
+        serde_yaml::Value::Tagged(tagged) => yaml_to_json(tagged.value)?,
+    })
 }
 
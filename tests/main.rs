@@ -3,13 +3,18 @@
     use std::fs::{self, File};
     use std::io::Write;
     use std::thread;
-    use config_ro::Config;
+    use config_ro::{Config, ConfigError, Origin};
 
     fn create_temp_config(name: &str, content: &str) {
         fs::create_dir("configs");
         let mut file = File::create(format!("configs/{}.json", name)).unwrap();
         writeln!(file, "{}", content).unwrap();
     }
+    fn create_temp_config_ext(name: &str, ext: &str, content: &str) {
+        fs::create_dir("configs");
+        let mut file = File::create(format!("configs/{}.{}", name, ext)).unwrap();
+        writeln!(file, "{}", content).unwrap();
+    }
     fn delete_temp() {
         fs::remove_dir_all("configs");
     }
@@ -179,6 +184,300 @@
         delete_temp()
     }
 
+    #[test]
+    fn test_config_loads_toml_file() {
+        let config_content = "key = \"value\"\n[nested]\nid = 2\n";
+        create_temp_config_ext("toml_config", "toml", config_content);
+
+        let config = Config::new("toml_config");
+
+        assert_eq!(config.get::<String>("key").unwrap(), "value");
+        assert_eq!(config.get::<i64>("nested.id").unwrap(), 2);
+        delete_temp()
+    }
+
+    #[test]
+    fn test_config_loads_yaml_file() {
+        let config_content = "key: value\nnested:\n  id: 2\n";
+        create_temp_config_ext("yaml_config", "yaml", config_content);
+
+        let config = Config::new("yaml_config");
+
+        assert_eq!(config.get::<String>("key").unwrap(), "value");
+        assert_eq!(config.get::<i64>("nested.id").unwrap(), 2);
+        delete_temp()
+    }
+
+    #[test]
+    fn test_config_prefers_json_over_other_formats() {
+        create_temp_config("mixed_config", r#"{"key": "from_json"}"#);
+        create_temp_config_ext("mixed_config", "toml", "key = \"from_toml\"\n");
+
+        let config = Config::new("mixed_config");
+
+        assert_eq!(config.get::<String>("key").unwrap(), "from_json");
+        delete_temp()
+    }
+
+    #[test]
+    fn test_env_override_takes_precedence() {
+        create_temp_config("env_config", r#"{"database": {"port": 5432}}"#);
+        std::env::set_var("ENV_CONFIG__DATABASE__PORT", "9999");
+
+        let config = Config::new("env_config");
+        let port: u16 = config.get("database.port").unwrap();
+
+        assert_eq!(port, 9999);
+        std::env::remove_var("ENV_CONFIG__DATABASE__PORT");
+        delete_temp()
+    }
+
+    #[test]
+    fn test_env_override_falls_back_to_raw_string() {
+        create_temp_config("env_str_config", r#"{"host": "localhost"}"#);
+        std::env::set_var("ENV_STR_CONFIG__HOST", "example.com");
+
+        let config = Config::new("env_str_config");
+        let host: String = config.get("host").unwrap();
+
+        assert_eq!(host, "example.com");
+        std::env::remove_var("ENV_STR_CONFIG__HOST");
+        delete_temp()
+    }
+
+    #[test]
+    fn test_no_env_override_uses_file_value() {
+        create_temp_config("no_env_config", r#"{"key": "file_value"}"#);
+
+        let config = Config::new("no_env_config");
+        let value: String = config.get("key").unwrap();
+
+        assert_eq!(value, "file_value");
+        delete_temp()
+    }
+
+    #[test]
+    fn test_set_override_takes_precedence_over_file() {
+        create_temp_config("override_config", r#"{"database": {"port": 5432}}"#);
+
+        let config = Config::new("override_config");
+        config.set_override("database.port", json!(6543));
+
+        let port: u16 = config.get("database.port").unwrap();
+
+        assert_eq!(port, 6543);
+        delete_temp()
+    }
+
+    #[test]
+    fn test_with_overrides_parses_key_value_pairs() {
+        create_temp_config("bulk_override_config", r#"{"db": {"host": "file_host"}}"#);
+
+        let config = Config::new("bulk_override_config");
+        config.with_overrides("db.host=localhost,db.port=5432");
+
+        let host: String = config.get("db.host").unwrap();
+        let port: u16 = config.get("db.port").unwrap();
+
+        assert_eq!(host, "localhost");
+        assert_eq!(port, 5432);
+        delete_temp()
+    }
+
+    #[test]
+    fn test_override_beats_env_override() {
+        create_temp_config("precedence_config", r#"{"key": "file_value"}"#);
+        std::env::set_var("PRECEDENCE_CONFIG__KEY", "env_value");
+
+        let config = Config::new("precedence_config");
+        config.set_override("key", json!("override_value"));
+
+        let value: String = config.get("key").unwrap();
+
+        assert_eq!(value, "override_value");
+        std::env::remove_var("PRECEDENCE_CONFIG__KEY");
+        delete_temp()
+    }
+
+    #[test]
+    fn test_reload_picks_up_file_changes() {
+        let config_content = r#"{"key": "value"}"#;
+        create_temp_config("reload_config", config_content);
+
+        let config = Config::new("reload_config");
+        assert_eq!(config.get::<String>("key").unwrap(), "value");
+
+        create_temp_config("reload_config", r#"{"key": "new_value"}"#);
+        config.reload();
+
+        assert_eq!(config.get::<String>("key").unwrap(), "new_value");
+        delete_temp()
+    }
+
+    #[test]
+    fn test_reload_bumps_generation() {
+        let config_content = r#"{"key": "value"}"#;
+        create_temp_config("generation_config", config_content);
+
+        let config = Config::new("generation_config");
+        let before = config.generation();
+        config.reload();
+        config.reload();
+
+        assert_eq!(config.generation(), before + 2);
+        delete_temp()
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to read config file:")]
+    fn test_reload_panics_if_file_removed() {
+        create_temp_config("reload_missing_config", r#"{"key": "value"}"#);
+
+        let config = Config::new("reload_missing_config");
+        delete_temp();
+        config.reload();
+    }
+
+    #[test]
+    fn test_layered_merges_objects_with_later_overriding() {
+        create_temp_config("defaults", r#"{"database": {"host": "localhost", "port": 5432}, "debug": false}"#);
+        create_temp_config("production", r#"{"database": {"host": "prod.example.com"}, "debug": true}"#);
+
+        let config = Config::layered(&["defaults", "production"]);
+
+        assert_eq!(config.get::<String>("database.host").unwrap(), "prod.example.com");
+        assert_eq!(config.get::<u16>("database.port").unwrap(), 5432);
+        assert_eq!(config.get::<bool>("debug").unwrap(), true);
+        delete_temp()
+    }
+
+    #[test]
+    fn test_layered_replaces_arrays_wholesale() {
+        create_temp_config("base_arr", r#"{"tags": ["a", "b"]}"#);
+        create_temp_config("override_arr", r#"{"tags": ["c"]}"#);
+
+        let config = Config::layered(&["base_arr", "override_arr"]);
+        let tags: Vec<String> = config.get("tags").unwrap();
+
+        assert_eq!(tags, vec!["c".to_string()]);
+        delete_temp()
+    }
+
+    #[test]
+    fn test_get_with_origin_reports_file_for_plain_value() {
+        create_temp_config("origin_config", r#"{"key": "value"}"#);
+
+        let config = Config::new("origin_config");
+        let (value, origin) = config.get_with_origin::<String>("key").unwrap();
+
+        assert_eq!(value, "value");
+        assert_eq!(origin, Origin::File("configs/origin_config.json".into()));
+        delete_temp()
+    }
+
+    #[test]
+    fn test_get_with_origin_reports_env_override() {
+        create_temp_config("origin_env_config", r#"{"key": "value"}"#);
+        std::env::set_var("ORIGIN_ENV_CONFIG__KEY", "from_env");
+
+        let config = Config::new("origin_env_config");
+        let (value, origin) = config.get_with_origin::<String>("key").unwrap();
+
+        assert_eq!(value, "from_env");
+        assert_eq!(origin, Origin::EnvOverride);
+        std::env::remove_var("ORIGIN_ENV_CONFIG__KEY");
+        delete_temp()
+    }
+
+    #[test]
+    fn test_get_with_origin_reports_runtime_override() {
+        create_temp_config("origin_override_config", r#"{"key": "value"}"#);
+
+        let config = Config::new("origin_override_config");
+        config.set_override("key", json!("overridden"));
+        let (value, origin) = config.get_with_origin::<String>("key").unwrap();
+
+        assert_eq!(value, "overridden");
+        assert_eq!(origin, Origin::RuntimeOverride);
+        delete_temp()
+    }
+
+    #[test]
+    fn test_get_with_origin_tracks_winning_file_in_layered_config() {
+        create_temp_config("origin_defaults", r#"{"database": {"host": "localhost", "port": 5432}}"#);
+        create_temp_config("origin_production", r#"{"database": {"host": "prod.example.com"}}"#);
+
+        let config = Config::layered(&["origin_defaults", "origin_production"]);
+        let (host, host_origin) = config.get_with_origin::<String>("database.host").unwrap();
+        let (port, port_origin) = config.get_with_origin::<u16>("database.port").unwrap();
+
+        assert_eq!(host, "prod.example.com");
+        assert_eq!(host_origin, Origin::File("configs/origin_production.json".into()));
+        assert_eq!(port, 5432);
+        assert_eq!(port_origin, Origin::File("configs/origin_defaults.json".into()));
+        delete_temp()
+    }
+
+    #[test]
+    fn test_try_new_returns_file_not_found_error() {
+        match Config::try_new("does_not_exist_config") {
+            Err(ConfigError::FileNotFound(_)) => {}
+            other => panic!("expected FileNotFound error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_try_new_returns_parse_error() {
+        create_temp_config("invalid_try_config", "invalid json");
+
+        match Config::try_new("invalid_try_config") {
+            Err(ConfigError::Parse { line, column, .. }) => {
+                assert!(line.is_some());
+                assert!(column.is_some());
+            }
+            other => panic!("expected Parse error, got {:?}", other.map(|_| ())),
+        }
+        delete_temp()
+    }
+
+    #[test]
+    fn test_try_new_succeeds_for_valid_config() {
+        create_temp_config("valid_try_config", r#"{"key": "value"}"#);
+
+        let config = Config::try_new("valid_try_config").unwrap();
+
+        assert_eq!(config.get::<String>("key").unwrap(), "value");
+        delete_temp()
+    }
+
+    #[test]
+    fn test_try_get_returns_type_mismatch_error() {
+        create_temp_config("mismatch_config", r#"{"key": "not_a_number"}"#);
+
+        let config = Config::new("mismatch_config");
+        let result = config.try_get::<u32>("key");
+
+        assert!(matches!(result, Err(ConfigError::TypeMismatch { .. })));
+        delete_temp()
+    }
+
+    #[test]
+    fn test_try_get_returns_ok_none_for_missing_key() {
+        create_temp_config("try_get_config", r#"{"key": "value"}"#);
+
+        let config = Config::new("try_get_config");
+        let result = config.try_get::<String>("missing_key").unwrap();
+
+        assert_eq!(result, None);
+        delete_temp()
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to read config file:")]
+    fn test_new_still_panics_on_missing_file() {
+        let _ = Config::new("still_missing_config");
+    }
+
     #[test]
     fn test_high_contention_scenario() {
         let config_content = r#"{"contended_key": "value"}"#;